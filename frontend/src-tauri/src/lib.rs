@@ -1,19 +1,83 @@
 use std::io::{Read, Write};
-use std::net::{SocketAddr, TcpStream};
+use std::net::{SocketAddr, TcpListener, TcpStream};
 use std::path::PathBuf;
 use std::process::{Child, Command};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::thread;
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
 
 // Global state to hold the backend process
 struct BackendProcess(Mutex<Option<Child>>);
 
-const BACKEND_PORT: u16 = 8000;
+// The port the currently-running sidecar was allocated, if any. Queryable by
+// the frontend via `get_backend_port` since it's no longer a fixed constant.
+struct BackendPort(Mutex<Option<u16>>);
+
+// Set by the window-close handler so the supervisor can tell a deliberate
+// shutdown apart from a crash and skip restarting the sidecar.
+struct ShutdownFlag(AtomicBool);
+
+// Fallback port used only in development, where the backend is started
+// manually (e.g. `uvicorn main:app --reload`) rather than as a sidecar.
+const DEV_BACKEND_PORT: u16 = 8000;
 const BACKEND_READY_TIMEOUT_SECONDS: u64 = 15;
 const BACKEND_READY_POLL_MS: u64 = 250;
 
+// Readiness contract the sidecar's health endpoint must satisfy: a bare 200
+// isn't enough, since the process can answer HTTP before its models/DB are
+// actually loaded.
+const BACKEND_HEALTH_PATH: &str = "/health";
+const BACKEND_HEALTH_CHECK_TIMEOUT_MS: u64 = 500;
+const BACKEND_READY_STATUS_VALUE: &str = "ok";
+
+// Graceful shutdown: give the sidecar a chance to close files/DB handles
+// before we resort to `child.kill()` (SIGKILL).
+const BACKEND_SHUTDOWN_PATH: &str = "/shutdown";
+const BACKEND_SHUTDOWN_GRACE_MS: u64 = 5000;
+const BACKEND_SHUTDOWN_POLL_MS: u64 = 100;
+
+const SUPERVISOR_POLL_MS: u64 = 1000;
+const SUPERVISOR_BASE_DELAY_MS: u64 = 500;
+const SUPERVISOR_MAX_DELAY_MS: u64 = 30_000;
+const SUPERVISOR_MAX_RESTART_ATTEMPTS: u32 = 6;
+const SUPERVISOR_HEALTHY_RESET_SECONDS: u64 = 30;
+
+/// Event name the frontend listens on via `@tauri-apps/api/event`.
+const BACKEND_STATUS_EVENT: &str = "backend://status";
+
+/// Lifecycle state of the backend sidecar, reported to the frontend so it can
+/// show a splash/reconnect UI instead of polling blindly.
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BackendState {
+    Starting,
+    Ready,
+    Failed,
+    Restarting,
+}
+
+#[derive(Clone, Serialize)]
+struct BackendStatusEvent {
+    state: BackendState,
+    detail: String,
+}
+
+/// Emits a `backend://status` event so the frontend can react to sidecar
+/// lifecycle transitions instead of inferring them from polling.
+fn emit_backend_status(app_handle: &AppHandle, state: BackendState, detail: impl Into<String>) {
+    let event = BackendStatusEvent {
+        state,
+        detail: detail.into(),
+    };
+
+    if let Err(err) = app_handle.emit(BACKEND_STATUS_EVENT, event) {
+        log::error!("Failed to emit backend status event: {}", err);
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -22,6 +86,9 @@ pub fn run() {
     .plugin(tauri_plugin_updater::Builder::new().build())
     .plugin(tauri_plugin_log::Builder::default().build())
     .manage(BackendProcess(Mutex::new(None)))
+    .manage(BackendPort(Mutex::new(None)))
+    .manage(ShutdownFlag(AtomicBool::new(false)))
+    .invoke_handler(tauri::generate_handler![get_backend_port])
     .setup(|app| {
       let handle = app.handle().clone();
 
@@ -29,20 +96,29 @@ pub fn run() {
       if !cfg!(debug_assertions) {
         start_backend_sidecar(handle);
       } else {
-        log::info!("Development mode: Backend should be started manually with `uvicorn main:app --reload` in backend directory");
+        log::info!(
+            "Development mode: Backend should be started manually with `uvicorn main:app --reload --port {}` in backend directory",
+            DEV_BACKEND_PORT
+        );
       }
 
       Ok(())
     })
     .on_window_event(|window, event| {
       if let tauri::WindowEvent::CloseRequested { .. } = event {
+        // Tell the supervisor this is a deliberate shutdown, not a crash.
+        if let Some(shutdown_flag) = window.try_state::<ShutdownFlag>() {
+          shutdown_flag.0.store(true, Ordering::Relaxed);
+        }
+
         // Cleanup backend process on window close
         if let Some(backend_state) = window.try_state::<BackendProcess>() {
           if let Ok(mut process_guard) = backend_state.0.lock() {
             if let Some(mut child) = process_guard.take() {
-              log::info!("Terminating backend process...");
-              let _ = child.kill();
-              let _ = child.wait();
+              let port = window.try_state::<BackendPort>()
+                .and_then(|state| state.0.lock().ok().map(|guard| *guard))
+                .flatten();
+              shutdown_backend_process(&mut child, port);
             }
           }
         }
@@ -53,65 +129,357 @@ pub fn run() {
 }
 
 fn start_backend_sidecar(app_handle: AppHandle) {
-    let handle_clone = app_handle.clone();
-
     tauri::async_runtime::spawn(async move {
-        log::info!("Starting backend sidecar...");
+        let supervisor_handle = app_handle.clone();
+        let became_ready =
+            tauri::async_runtime::spawn_blocking(move || launch_backend_sidecar(&app_handle))
+                .await
+                .unwrap_or(false);
+
+        if became_ready {
+            start_supervisor(supervisor_handle);
+        }
+    });
+}
 
-        // Get and start the sidecar binary path.
-        let result = tauri::async_runtime::spawn_blocking(move || -> Result<Child, String> {
-            let sidecar_path = resolve_backend_sidecar_path(&handle_clone).ok_or_else(|| {
-                "Unable to resolve backend sidecar path in app bundle".to_string()
-            })?;
+/// Spawns the sidecar process, stores its handle, and blocks until it either
+/// reports ready or the readiness timeout elapses. Safe to call from a
+/// blocking thread (used both for the initial launch and for supervised
+/// restarts).
+fn launch_backend_sidecar(app_handle: &AppHandle) -> bool {
+    log::info!("Starting backend sidecar...");
+    emit_backend_status(app_handle, BackendState::Starting, "Launching backend sidecar");
+
+    let port = match allocate_backend_port() {
+        Ok(port) => port,
+        Err(e) => {
+            log::error!("{}", e);
+            emit_backend_status(app_handle, BackendState::Failed, e);
+            return false;
+        }
+    };
 
-            log::info!("Backend sidecar path: {:?}", sidecar_path);
+    let child = match resolve_backend_sidecar_path(app_handle) {
+        Some(sidecar_path) => {
+            log::info!("Backend sidecar path: {:?}, port: {}", sidecar_path, port);
+
+            Command::new(&sidecar_path)
+                .env("BACKEND_PORT", port.to_string())
+                .spawn()
+                .map_err(|err| {
+                    format!(
+                        "Failed to start backend sidecar at {:?}: {}",
+                        sidecar_path, err
+                    )
+                })
+        }
+        None => Err("Unable to resolve backend sidecar path in app bundle".to_string()),
+    };
 
-            Command::new(&sidecar_path).spawn().map_err(|err| {
-                format!(
-                    "Failed to start backend sidecar at {:?}: {}",
-                    sidecar_path, err
-                )
-            })
+    let child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            log::error!("Failed to start backend sidecar: {}", e);
+            emit_backend_status(app_handle, BackendState::Failed, e);
+            return false;
+        }
+    };
+
+    log::info!("Backend sidecar started successfully");
+    store_backend_child(app_handle, child);
+    store_backend_port(app_handle, port);
+
+    // A deliberate window close can land between storing the child above and
+    // here, racing `on_window_event`'s `process_guard.take()` — if it landed
+    // just before we stored the child, it saw `None` and won't shut anything
+    // down. Re-check and reap immediately so that race can't leave this
+    // freshly spawned sidecar running as an orphan after the window closes.
+    if is_shutting_down(app_handle) {
+        log::info!("Shutdown requested while backend sidecar was starting; stopping it");
+        reap_backend_child(app_handle, Some(port));
+        return false;
+    }
+
+    let ready_result = wait_for_backend_ready(
+        port,
+        Duration::from_secs(BACKEND_READY_TIMEOUT_SECONDS),
+        Some(app_handle),
+    );
+
+    match ready_result {
+        Ok(()) => {
+            log::info!("Backend ready on http://127.0.0.1:{}", port);
+            emit_backend_status(
+                app_handle,
+                BackendState::Ready,
+                format!("Backend ready on http://127.0.0.1:{}", port),
+            );
+            true
+        }
+        Err(detail) => {
+            let detail = format!(
+                "Backend did not become ready within {} seconds: {}",
+                BACKEND_READY_TIMEOUT_SECONDS, detail
+            );
+            log::error!("{}", detail);
+            emit_backend_status(app_handle, BackendState::Failed, detail);
+            false
+        }
+    }
+}
+
+/// Binds an ephemeral port to let the OS pick a free one, then releases it
+/// immediately so the sidecar can bind it instead. This avoids the fixed
+/// `8000` colliding with another process or another instance of this app.
+fn allocate_backend_port() -> Result<u16, String> {
+    TcpListener::bind(("127.0.0.1", 0))
+        .map_err(|err| format!("Failed to allocate a backend port: {}", err))
+        .map(|listener| {
+            listener
+                .local_addr()
+                .expect("bound TcpListener has a local address")
+                .port()
         })
-        .await;
+}
 
-        match result {
-            Ok(Ok(child)) => {
-                log::info!("Backend sidecar started successfully");
+fn store_backend_child(app_handle: &AppHandle, child: Child) {
+    if let Some(backend_state) = app_handle.try_state::<BackendProcess>() {
+        if let Ok(mut process_guard) = backend_state.0.lock() {
+            *process_guard = Some(child);
+        }
+    }
+}
 
-                // Store the process handle
-                if let Some(backend_state) = app_handle.try_state::<BackendProcess>() {
-                    if let Ok(mut process_guard) = backend_state.0.lock() {
-                        *process_guard = Some(child);
-                    }
-                }
+/// Removes and returns the currently-stored child, if any, so the caller can
+/// replace it with a freshly spawned one without leaking the old handle.
+fn take_backend_child(app_handle: &AppHandle) -> Option<Child> {
+    let backend_state = app_handle.try_state::<BackendProcess>()?;
+    let mut process_guard = backend_state.0.lock().ok()?;
+    process_guard.take()
+}
 
-                let ready_result = tauri::async_runtime::spawn_blocking(|| {
-                    wait_for_backend_ready(Duration::from_secs(BACKEND_READY_TIMEOUT_SECONDS))
-                })
-                .await;
-
-                match ready_result {
-                    Ok(true) => {
-                        log::info!("Backend ready on http://127.0.0.1:{}", BACKEND_PORT);
-                    }
-                    Ok(false) => {
-                        log::error!(
-                            "Backend did not become ready within {} seconds",
-                            BACKEND_READY_TIMEOUT_SECONDS
-                        );
-                    }
-                    Err(err) => {
-                        log::error!("Backend readiness check task failed: {}", err);
-                    }
+/// Takes the stored child out of shared state and makes sure it isn't left
+/// running: reaps it if it already exited, otherwise shuts it down the same
+/// way the window-close handler does. Dropping a `Child` does not kill the
+/// process, so this must run before the supervisor replaces it with a new
+/// one (on restart) or gives up (on exhaustion) — otherwise the old,
+/// unhealthy backend keeps running as an orphan.
+fn reap_backend_child(app_handle: &AppHandle, port: Option<u16>) {
+    let Some(mut child) = take_backend_child(app_handle) else {
+        return;
+    };
+
+    match child.try_wait() {
+        Ok(Some(_)) => {
+            let _ = child.wait();
+        }
+        _ => {
+            log::warn!("Stopping backend sidecar that is still running");
+            shutdown_backend_process(&mut child, port);
+        }
+    }
+}
+
+fn store_backend_port(app_handle: &AppHandle, port: u16) {
+    if let Some(port_state) = app_handle.try_state::<BackendPort>() {
+        if let Ok(mut port_guard) = port_state.0.lock() {
+            *port_guard = Some(port);
+        }
+    }
+}
+
+/// Returns the port the running backend sidecar was allocated, if any, so
+/// the frontend can reach it without assuming a fixed port.
+#[tauri::command]
+fn get_backend_port(app_handle: AppHandle) -> Option<u16> {
+    app_handle
+        .try_state::<BackendPort>()
+        .and_then(|state| state.0.lock().ok().map(|guard| *guard))
+        .flatten()
+}
+
+/// Tries an orderly shutdown first (POST `/shutdown`, then wait up to the
+/// grace period), falling back to `child.kill()` only if the process is
+/// still alive afterwards. Logs which path was taken so a shutdown hang is
+/// diagnosable instead of silently always hard-killing.
+fn shutdown_backend_process(child: &mut Child, port: Option<u16>) {
+    let requested = port.is_some_and(send_shutdown_request);
+
+    if requested {
+        log::info!(
+            "Requested graceful backend shutdown via POST {}",
+            BACKEND_SHUTDOWN_PATH
+        );
+
+        if wait_for_backend_exit(child, Duration::from_millis(BACKEND_SHUTDOWN_GRACE_MS)) {
+            log::info!("Backend exited gracefully after shutdown request");
+            return;
+        }
+
+        log::warn!(
+            "Backend did not exit within the {}ms grace period; killing it",
+            BACKEND_SHUTDOWN_GRACE_MS
+        );
+    } else {
+        log::warn!("Could not request a graceful backend shutdown; killing it directly");
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+    log::info!("Backend process terminated");
+}
+
+/// Best-effort `POST /shutdown`. Returns whether the request was sent, not
+/// whether the backend acted on it.
+fn send_shutdown_request(port: u16) -> bool {
+    let socket = SocketAddr::from(([127, 0, 0, 1], port));
+    let timeout = Duration::from_millis(BACKEND_HEALTH_CHECK_TIMEOUT_MS);
+
+    let mut stream = match TcpStream::connect_timeout(&socket, timeout) {
+        Ok(stream) => stream,
+        Err(_) => return false,
+    };
+    let _ = stream.set_write_timeout(Some(timeout));
+
+    let request = format!(
+        "POST {} HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        BACKEND_SHUTDOWN_PATH
+    );
+    stream.write_all(request.as_bytes()).is_ok()
+}
+
+/// Polls `child.try_wait()` until it exits or `timeout` elapses.
+fn wait_for_backend_exit(child: &mut Child, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => return true,
+            Ok(None) => {}
+            Err(_) => return false,
+        }
+
+        if Instant::now() >= deadline {
+            return false;
+        }
+
+        thread::sleep(Duration::from_millis(BACKEND_SHUTDOWN_POLL_MS));
+    }
+}
+
+fn is_shutting_down(app_handle: &AppHandle) -> bool {
+    app_handle
+        .try_state::<ShutdownFlag>()
+        .is_some_and(|flag| flag.0.load(Ordering::Relaxed))
+}
+
+/// `true` if the stored child has exited (or there is no child to check).
+fn backend_child_has_exited(app_handle: &AppHandle) -> bool {
+    let Some(backend_state) = app_handle.try_state::<BackendProcess>() else {
+        return true;
+    };
+    let Ok(mut process_guard) = backend_state.0.lock() else {
+        return true;
+    };
+
+    match process_guard.as_mut() {
+        Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+        None => true,
+    }
+}
+
+/// `delay = min(MAX_DELAY, BASE_DELAY * 2^attempt)`, plus jitter uniformly
+/// distributed in `[0, delay/2]` so simultaneously-crashing instances don't
+/// all retry in lockstep.
+fn supervisor_backoff_delay(attempt: u32) -> Duration {
+    let scaled = SUPERVISOR_BASE_DELAY_MS.saturating_mul(1u64 << attempt.min(20));
+    let delay = scaled.min(SUPERVISOR_MAX_DELAY_MS);
+    let jitter = if delay == 0 { 0 } else { random_u64() % (delay / 2 + 1) };
+    Duration::from_millis(delay + jitter)
+}
+
+/// Cheap source of non-deterministic jitter using the OS-seeded hasher that
+/// `std` already pulls in for `HashMap`, so we don't need a `rand` dependency
+/// just to jitter a retry delay.
+fn random_u64() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    RandomState::new().build_hasher().finish()
+}
+
+/// Spawned once the sidecar first becomes ready. Polls its health and
+/// restarts it with exponential backoff if it crashes or stops responding,
+/// giving up after `SUPERVISOR_MAX_RESTART_ATTEMPTS` consecutive failures.
+fn start_supervisor(app_handle: AppHandle) {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut attempt: u32 = 0;
+        let mut healthy_since = Instant::now();
+
+        loop {
+            thread::sleep(Duration::from_millis(SUPERVISOR_POLL_MS));
+
+            if is_shutting_down(&app_handle) {
+                log::info!("Supervisor stopping: shutdown in progress");
+                return;
+            }
+
+            let exited = backend_child_has_exited(&app_handle);
+            let healthy = !exited
+                && get_backend_port(app_handle.clone())
+                    .is_some_and(|port| matches!(check_backend_readiness(port), BackendReadiness::Ready));
+
+            if healthy {
+                if attempt > 0 && healthy_since.elapsed() >= Duration::from_secs(SUPERVISOR_HEALTHY_RESET_SECONDS) {
+                    log::info!("Backend has been healthy; resetting restart attempt counter");
+                    attempt = 0;
                 }
+                continue;
             }
-            Ok(Err(e)) => {
-                log::error!("Failed to start backend sidecar: {}", e);
+
+            if exited {
+                log::warn!("Backend sidecar exited unexpectedly");
+            } else {
+                log::warn!("Backend sidecar failed its health check");
             }
-            Err(e) => {
-                log::error!("Failed to start backend sidecar task: {}", e);
+
+            // The old child is either already dead or alive-but-unhealthy;
+            // reap it now so neither the restart below nor giving up below
+            // leaves it running as an orphan.
+            reap_backend_child(&app_handle, get_backend_port(app_handle.clone()));
+
+            if attempt >= SUPERVISOR_MAX_RESTART_ATTEMPTS {
+                let detail = format!(
+                    "Backend sidecar failed to recover after {} attempts",
+                    SUPERVISOR_MAX_RESTART_ATTEMPTS
+                );
+                log::error!("{}", detail);
+                emit_backend_status(&app_handle, BackendState::Failed, detail);
+                return;
             }
+
+            let delay = supervisor_backoff_delay(attempt);
+            attempt += 1;
+
+            emit_backend_status(
+                &app_handle,
+                BackendState::Restarting,
+                format!(
+                    "Restarting backend sidecar in {:.1}s (attempt {}/{})",
+                    delay.as_secs_f64(),
+                    attempt,
+                    SUPERVISOR_MAX_RESTART_ATTEMPTS
+                ),
+            );
+            thread::sleep(delay);
+
+            if is_shutting_down(&app_handle) {
+                log::info!("Supervisor stopping: shutdown in progress");
+                return;
+            }
+
+            launch_backend_sidecar(&app_handle);
+            healthy_since = Instant::now();
         }
     });
 }
@@ -156,45 +524,107 @@ fn resolve_backend_sidecar_path(app_handle: &AppHandle) -> Option<PathBuf> {
     None
 }
 
-fn wait_for_backend_ready(timeout: Duration) -> bool {
+/// Polls the health endpoint until it reports the readiness contract
+/// satisfied or `timeout` elapses. On failure, returns the last readiness
+/// detail (e.g. "model still loading") instead of a bare timeout.
+fn wait_for_backend_ready(
+    port: u16,
+    timeout: Duration,
+    app_handle: Option<&AppHandle>,
+) -> Result<(), String> {
     let deadline = Instant::now() + timeout;
+    let mut attempt: u32 = 0;
+    let mut last_detail = "backend not yet reachable".to_string();
 
     loop {
-        if backend_health_ok(BACKEND_PORT) {
-            return true;
+        match check_backend_readiness(port) {
+            BackendReadiness::Ready => return Ok(()),
+            BackendReadiness::NotReady(detail) => last_detail = detail,
         }
 
         if Instant::now() >= deadline {
-            return false;
+            return Err(last_detail);
+        }
+
+        attempt += 1;
+        if let Some(app_handle) = app_handle {
+            emit_backend_status(
+                app_handle,
+                BackendState::Starting,
+                format!(
+                    "Waiting for backend to become ready (attempt {}): {}",
+                    attempt, last_detail
+                ),
+            );
         }
 
         thread::sleep(Duration::from_millis(BACKEND_READY_POLL_MS));
     }
 }
 
-fn backend_health_ok(port: u16) -> bool {
+/// Outcome of a single readiness probe, with a human-readable reason when the
+/// backend isn't ready yet so failures say *why* rather than just "timeout".
+enum BackendReadiness {
+    Ready,
+    NotReady(String),
+}
+
+/// Body contract the health endpoint must satisfy, e.g.
+/// `{ "status": "ok", "ready": true }`. `detail` is optional context the
+/// backend can supply to explain a not-ready state.
+#[derive(Deserialize)]
+struct BackendHealthBody {
+    status: Option<String>,
+    ready: Option<bool>,
+    detail: Option<String>,
+}
+
+/// Connects to the health endpoint and checks both the HTTP status line (a
+/// fast pre-filter) and the JSON body against the readiness contract, so a
+/// `200` the backend returns before it has actually finished loading isn't
+/// mistaken for ready.
+fn check_backend_readiness(port: u16) -> BackendReadiness {
     let socket = SocketAddr::from(([127, 0, 0, 1], port));
-    let timeout = Duration::from_millis(500);
+    let timeout = Duration::from_millis(BACKEND_HEALTH_CHECK_TIMEOUT_MS);
 
     let mut stream = match TcpStream::connect_timeout(&socket, timeout) {
         Ok(stream) => stream,
-        Err(_) => return false,
+        Err(err) => return BackendReadiness::NotReady(format!("connection failed: {}", err)),
     };
 
     let _ = stream.set_read_timeout(Some(timeout));
     let _ = stream.set_write_timeout(Some(timeout));
 
-    let request = b"GET /health HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n";
-    if stream.write_all(request).is_err() {
-        return false;
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n",
+        BACKEND_HEALTH_PATH
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return BackendReadiness::NotReady("failed to send health request".to_string());
     }
 
     let mut response = String::new();
     if stream.read_to_string(&mut response).is_err() {
-        return false;
+        return BackendReadiness::NotReady("failed to read health response".to_string());
+    }
+
+    if !response_is_success(&response) {
+        let status_line = response.lines().next().unwrap_or("<empty response>");
+        return BackendReadiness::NotReady(format!("health endpoint returned {}", status_line));
     }
 
-    response_is_success(&response)
+    match serde_json::from_str::<BackendHealthBody>(response_body(&response)) {
+        Ok(body) if body.status.as_deref() == Some(BACKEND_READY_STATUS_VALUE) && body.ready == Some(true) => {
+            BackendReadiness::Ready
+        }
+        Ok(body) => BackendReadiness::NotReady(
+            body.detail
+                .unwrap_or_else(|| "backend reported it is not ready yet".to_string()),
+        ),
+        Err(err) => {
+            BackendReadiness::NotReady(format!("could not parse health response body: {}", err))
+        }
+    }
 }
 
 fn response_is_success(response: &str) -> bool {
@@ -204,9 +634,17 @@ fn response_is_success(response: &str) -> bool {
         .is_some_and(|line| line.starts_with("HTTP/1.1 200") || line.starts_with("HTTP/1.0 200"))
 }
 
+/// Splits the HTTP head from the body on the blank line separating them.
+fn response_body(response: &str) -> &str {
+    response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body)
+        .unwrap_or("")
+}
+
 #[cfg(test)]
 mod tests {
-    use super::response_is_success;
+    use super::{response_body, response_is_success};
 
     #[test]
     fn response_is_success_for_http_11_200() {
@@ -230,4 +668,15 @@ mod tests {
     fn response_is_not_success_for_invalid_payload() {
         assert!(!response_is_success("not-http"));
     }
+
+    #[test]
+    fn response_body_extracts_content_after_blank_line() {
+        let response = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"ready\":true}";
+        assert_eq!(response_body(response), "{\"ready\":true}");
+    }
+
+    #[test]
+    fn response_body_is_empty_without_a_blank_line_separator() {
+        assert_eq!(response_body("not-http"), "");
+    }
 }